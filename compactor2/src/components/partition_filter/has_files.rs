@@ -0,0 +1,79 @@
+use std::fmt::Display;
+
+use async_trait::async_trait;
+use data_types::{ParquetFile, PartitionId};
+
+use crate::error::DynError;
+
+use super::{FilterOutcome, PartitionFilter};
+
+/// Filters out partitions that do not have any parquet files at all.
+#[derive(Debug, Default)]
+pub struct HasFilesPartitionFilter;
+
+impl HasFilesPartitionFilter {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Display for HasFilesPartitionFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "has_files")
+    }
+}
+
+#[async_trait]
+impl PartitionFilter for HasFilesPartitionFilter {
+    async fn apply(
+        &self,
+        partition_id: PartitionId,
+        files: &[ParquetFile],
+    ) -> Result<bool, DynError> {
+        Ok(self.apply_with_reason(partition_id, files).await?.keep())
+    }
+
+    async fn apply_with_reason(
+        &self,
+        _partition_id: PartitionId,
+        files: &[ParquetFile],
+    ) -> Result<FilterOutcome, DynError> {
+        Ok(if files.is_empty() {
+            FilterOutcome::Filtered {
+                reason: "no_files",
+            }
+        } else {
+            FilterOutcome::Keep
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(HasFilesPartitionFilter::new().to_string(), "has_files");
+    }
+
+    #[tokio::test]
+    async fn test_apply() {
+        let filter = HasFilesPartitionFilter::new();
+        assert!(!filter.apply(PartitionId::new(1), &[]).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_apply_with_reason() {
+        let filter = HasFilesPartitionFilter::new();
+        assert_eq!(
+            filter
+                .apply_with_reason(PartitionId::new(1), &[])
+                .await
+                .unwrap(),
+            FilterOutcome::Filtered {
+                reason: "no_files"
+            }
+        );
+    }
+}
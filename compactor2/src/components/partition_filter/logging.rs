@@ -0,0 +1,85 @@
+use std::fmt::Display;
+
+use async_trait::async_trait;
+use data_types::{ParquetFile, PartitionId};
+use observability_deps::tracing::info;
+
+use crate::error::DynError;
+
+use super::{FilterOutcome, PartitionFilter};
+
+/// Logs the outcome of the wrapped filter.
+#[derive(Debug)]
+pub struct LoggingPartitionFilterWrapper<T>
+where
+    T: PartitionFilter,
+{
+    inner: T,
+}
+
+impl<T> LoggingPartitionFilterWrapper<T>
+where
+    T: PartitionFilter,
+{
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T> Display for LoggingPartitionFilterWrapper<T>
+where
+    T: PartitionFilter,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+#[async_trait]
+impl<T> PartitionFilter for LoggingPartitionFilterWrapper<T>
+where
+    T: PartitionFilter,
+{
+    async fn apply(
+        &self,
+        partition_id: PartitionId,
+        files: &[ParquetFile],
+    ) -> Result<bool, DynError> {
+        Ok(self.apply_with_reason(partition_id, files).await?.keep())
+    }
+
+    async fn apply_with_reason(
+        &self,
+        partition_id: PartitionId,
+        files: &[ParquetFile],
+    ) -> Result<FilterOutcome, DynError> {
+        let res = self.inner.apply_with_reason(partition_id, files).await;
+        match &res {
+            Ok(FilterOutcome::Keep) => {
+                info!(partition_id = partition_id.get(), filter = %self.inner, "partition kept");
+            }
+            Ok(FilterOutcome::Filtered { reason }) => {
+                info!(partition_id = partition_id.get(), filter = %self.inner, reason, "partition filtered out");
+            }
+            Err(e) => {
+                info!(partition_id = partition_id.get(), filter = %self.inner, %e, "error evaluating filter, marking partition as skipped");
+            }
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::partition_filter::{FalsePartitionFilter, TruePartitionFilter};
+
+    #[tokio::test]
+    async fn test_apply() {
+        let filter = LoggingPartitionFilterWrapper::new(TruePartitionFilter);
+        assert!(filter.apply(PartitionId::new(1), &[]).await.unwrap());
+
+        let filter = LoggingPartitionFilterWrapper::new(FalsePartitionFilter);
+        assert!(!filter.apply(PartitionId::new(1), &[]).await.unwrap());
+    }
+}
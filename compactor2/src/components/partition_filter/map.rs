@@ -0,0 +1,100 @@
+use std::fmt::Display;
+
+use async_trait::async_trait;
+use data_types::{ParquetFile, PartitionId};
+use futures::future::BoxFuture;
+
+use crate::error::DynError;
+
+use super::PartitionFilter;
+
+type MapFn = Box<
+    dyn for<'a> Fn(PartitionId, &'a [ParquetFile]) -> BoxFuture<'a, Result<bool, DynError>>
+        + Send
+        + Sync,
+>;
+
+/// Adapts an arbitrary async predicate into a [`PartitionFilter`] without requiring a bespoke
+/// struct per use case.
+pub struct MapPartitionFilter {
+    name: &'static str,
+    f: MapFn,
+}
+
+impl MapPartitionFilter {
+    /// `f` must return the future already boxed (e.g. via `Box::pin(async move { .. })`), so it
+    /// can borrow `files` across the `.await` point rather than being limited to predicates that
+    /// only snapshot data out of the slice synchronously before awaiting.
+    pub fn new<F>(name: &'static str, f: F) -> Self
+    where
+        F: for<'a> Fn(PartitionId, &'a [ParquetFile]) -> BoxFuture<'a, Result<bool, DynError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self {
+            name,
+            f: Box::new(f),
+        }
+    }
+}
+
+impl std::fmt::Debug for MapPartitionFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MapPartitionFilter")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Display for MapPartitionFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[async_trait]
+impl PartitionFilter for MapPartitionFilter {
+    async fn apply(
+        &self,
+        partition_id: PartitionId,
+        files: &[ParquetFile],
+    ) -> Result<bool, DynError> {
+        (self.f)(partition_id, files).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let filter = MapPartitionFilter::new("my_predicate", |_, _| Box::pin(async { Ok(true) }));
+        assert_eq!(filter.to_string(), "my_predicate");
+    }
+
+    #[tokio::test]
+    async fn test_apply() {
+        let filter = MapPartitionFilter::new("has_two_files", |_, files| {
+            Box::pin(async move { Ok(files.len() == 2) })
+        });
+        assert!(!filter.apply(PartitionId::new(1), &[]).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_apply_holds_borrow_across_await() {
+        // Regression test: the predicate awaits an inner future while still holding the `files`
+        // borrow, which requires the HRTB bound on `MapFn` rather than a separate `Fut` type
+        // parameter.
+        async fn file_count_async(files: &[ParquetFile]) -> usize {
+            tokio::task::yield_now().await;
+            files.len()
+        }
+
+        let filter = MapPartitionFilter::new("async_has_two_files", |_, files| {
+            Box::pin(async move { Ok(file_count_async(files).await == 2) })
+        });
+        assert!(!filter.apply(PartitionId::new(1), &[]).await.unwrap());
+    }
+}
@@ -0,0 +1,100 @@
+use std::fmt::Display;
+
+use async_trait::async_trait;
+use data_types::{ParquetFile, PartitionId};
+
+use crate::error::DynError;
+
+use super::{FilterOutcome, PartitionFilter};
+
+/// Filters out partitions that have more than `max_files` parquet files.
+///
+/// This exists to bound the amount of work a single compaction plan can take on; partitions with
+/// an extreme number of files are better handled by a dedicated, more conservative plan.
+#[derive(Debug)]
+pub struct MaxFilesPartitionFilter {
+    max_files: usize,
+}
+
+impl MaxFilesPartitionFilter {
+    pub fn new(max_files: usize) -> Self {
+        Self { max_files }
+    }
+}
+
+impl Display for MaxFilesPartitionFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "max_files")
+    }
+}
+
+#[async_trait]
+impl PartitionFilter for MaxFilesPartitionFilter {
+    async fn apply(
+        &self,
+        partition_id: PartitionId,
+        files: &[ParquetFile],
+    ) -> Result<bool, DynError> {
+        Ok(self.apply_with_reason(partition_id, files).await?.keep())
+    }
+
+    async fn apply_with_reason(
+        &self,
+        _partition_id: PartitionId,
+        files: &[ParquetFile],
+    ) -> Result<FilterOutcome, DynError> {
+        Ok(if files.len() > self.max_files {
+            FilterOutcome::Filtered {
+                reason: "too_many_files",
+            }
+        } else {
+            FilterOutcome::Keep
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iox_tests::ParquetFileBuilder;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(MaxFilesPartitionFilter::new(1).to_string(), "max_files");
+    }
+
+    #[tokio::test]
+    async fn test_apply() {
+        let filter = MaxFilesPartitionFilter::new(1);
+        assert!(filter.apply(PartitionId::new(1), &[]).await.unwrap());
+
+        let files = vec![
+            ParquetFileBuilder::new(1).build(),
+            ParquetFileBuilder::new(2).build(),
+        ];
+        assert!(!filter.apply(PartitionId::new(1), &files).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_apply_with_reason() {
+        let filter = MaxFilesPartitionFilter::new(0);
+        assert_eq!(
+            filter
+                .apply_with_reason(PartitionId::new(1), &[])
+                .await
+                .unwrap(),
+            FilterOutcome::Keep
+        );
+
+        let files = vec![ParquetFileBuilder::new(1).build()];
+        assert_eq!(
+            filter
+                .apply_with_reason(PartitionId::new(1), &files)
+                .await
+                .unwrap(),
+            FilterOutcome::Filtered {
+                reason: "too_many_files"
+            }
+        );
+    }
+}
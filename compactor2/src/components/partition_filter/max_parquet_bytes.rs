@@ -0,0 +1,101 @@
+use std::fmt::Display;
+
+use async_trait::async_trait;
+use data_types::{ParquetFile, PartitionId};
+
+use crate::error::DynError;
+
+use super::{FilterOutcome, PartitionFilter};
+
+/// Filters out partitions whose total parquet file size exceeds `max_bytes`.
+#[derive(Debug)]
+pub struct MaxParquetBytesPartitionFilter {
+    max_bytes: usize,
+}
+
+impl MaxParquetBytesPartitionFilter {
+    pub fn new(max_bytes: usize) -> Self {
+        Self { max_bytes }
+    }
+
+    fn total_bytes(files: &[ParquetFile]) -> usize {
+        files.iter().map(|f| f.file_size_bytes as usize).sum()
+    }
+}
+
+impl Display for MaxParquetBytesPartitionFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "max_parquet_bytes")
+    }
+}
+
+#[async_trait]
+impl PartitionFilter for MaxParquetBytesPartitionFilter {
+    async fn apply(
+        &self,
+        partition_id: PartitionId,
+        files: &[ParquetFile],
+    ) -> Result<bool, DynError> {
+        Ok(self.apply_with_reason(partition_id, files).await?.keep())
+    }
+
+    async fn apply_with_reason(
+        &self,
+        _partition_id: PartitionId,
+        files: &[ParquetFile],
+    ) -> Result<FilterOutcome, DynError> {
+        Ok(if Self::total_bytes(files) > self.max_bytes {
+            FilterOutcome::Filtered {
+                reason: "over_byte_budget",
+            }
+        } else {
+            FilterOutcome::Keep
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iox_tests::ParquetFileBuilder;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            MaxParquetBytesPartitionFilter::new(1).to_string(),
+            "max_parquet_bytes"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_apply() {
+        let filter = MaxParquetBytesPartitionFilter::new(0);
+        assert!(filter.apply(PartitionId::new(1), &[]).await.unwrap());
+
+        let files = vec![ParquetFileBuilder::new(1).with_file_size_bytes(10).build()];
+        assert!(!filter.apply(PartitionId::new(1), &files).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_apply_with_reason() {
+        let filter = MaxParquetBytesPartitionFilter::new(0);
+        assert_eq!(
+            filter
+                .apply_with_reason(PartitionId::new(1), &[])
+                .await
+                .unwrap(),
+            FilterOutcome::Keep
+        );
+
+        let files = vec![ParquetFileBuilder::new(1).with_file_size_bytes(10).build()];
+        assert_eq!(
+            filter
+                .apply_with_reason(PartitionId::new(1), &files)
+                .await
+                .unwrap(),
+            FilterOutcome::Filtered {
+                reason: "over_byte_budget"
+            }
+        );
+    }
+}
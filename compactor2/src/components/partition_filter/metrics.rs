@@ -0,0 +1,99 @@
+use std::fmt::Display;
+
+use async_trait::async_trait;
+use data_types::{ParquetFile, PartitionId};
+use metric::{Metric, Registry, U64Counter};
+
+use crate::error::DynError;
+
+use super::{FilterOutcome, PartitionFilter};
+
+const METRIC_NAME_PARTITION_FILTER_COUNT: &str = "iox_compactor_partition_filter_count";
+
+/// Records, per reason, how many partitions the wrapped filter keeps vs. excludes from
+/// compaction.
+#[derive(Debug)]
+pub struct MetricsPartitionFilterWrapper<T>
+where
+    T: PartitionFilter,
+{
+    inner: T,
+    metric: Metric<U64Counter>,
+}
+
+impl<T> MetricsPartitionFilterWrapper<T>
+where
+    T: PartitionFilter,
+{
+    pub fn new(inner: T, registry: &Registry) -> Self {
+        let metric = registry.register_metric(
+            METRIC_NAME_PARTITION_FILTER_COUNT,
+            "Number of times the compactor kept or filtered out a partition, by reason",
+        );
+        Self { inner, metric }
+    }
+
+    fn reason_for(outcome: FilterOutcome) -> &'static str {
+        match outcome {
+            FilterOutcome::Keep => "kept",
+            FilterOutcome::Filtered { reason } => reason,
+        }
+    }
+}
+
+impl<T> Display for MetricsPartitionFilterWrapper<T>
+where
+    T: PartitionFilter,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+#[async_trait]
+impl<T> PartitionFilter for MetricsPartitionFilterWrapper<T>
+where
+    T: PartitionFilter,
+{
+    async fn apply(
+        &self,
+        partition_id: PartitionId,
+        files: &[ParquetFile],
+    ) -> Result<bool, DynError> {
+        Ok(self.apply_with_reason(partition_id, files).await?.keep())
+    }
+
+    async fn apply_with_reason(
+        &self,
+        partition_id: PartitionId,
+        files: &[ParquetFile],
+    ) -> Result<FilterOutcome, DynError> {
+        let outcome = self.inner.apply_with_reason(partition_id, files).await?;
+        self.metric
+            .recorder(&[("reason", Self::reason_for(outcome))])
+            .inc(1);
+        Ok(outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::partition_filter::{FalsePartitionFilter, TruePartitionFilter};
+
+    #[tokio::test]
+    async fn test_apply_counts_by_reason() {
+        let registry = Registry::new();
+        let filter = MetricsPartitionFilterWrapper::new(TruePartitionFilter, &registry);
+        filter.apply(PartitionId::new(1), &[]).await.unwrap();
+
+        let filter = MetricsPartitionFilterWrapper::new(FalsePartitionFilter, &registry);
+        filter.apply(PartitionId::new(1), &[]).await.unwrap();
+
+        let metric = registry.get_instrument::<Metric<U64Counter>>(
+            METRIC_NAME_PARTITION_FILTER_COUNT,
+        ).unwrap();
+        assert_eq!(metric.get_observer(&[("reason", "kept")]).unwrap().fetch(), 1);
+        assert_eq!(metric.get_observer(&[("reason", "unknown")]).unwrap().fetch(), 1);
+    }
+}
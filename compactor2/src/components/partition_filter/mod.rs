@@ -10,11 +10,15 @@ pub mod greater_matching_files;
 pub mod has_files;
 pub mod has_matching_file;
 pub mod logging;
+pub mod map;
 pub mod max_files;
 pub mod max_parquet_bytes;
 pub mod metrics;
 pub mod never_skipped;
+pub mod not;
 pub mod or;
+pub mod shard;
+pub mod xor;
 
 /// Filters partition based on ID and parquet files.
 ///
@@ -30,6 +34,47 @@ pub trait PartitionFilter: Debug + Display + Send + Sync {
         partition_id: PartitionId,
         files: &[ParquetFile],
     ) -> Result<bool, DynError>;
+
+    /// Like [`Self::apply`] but returns a [`FilterOutcome`] that carries a reason when the
+    /// partition is filtered out.
+    ///
+    /// The default implementation adapts [`Self::apply`]'s bare bool into a generic
+    /// [`FilterOutcome::Filtered`], so existing bool-returning filters keep compiling unchanged.
+    /// Filters that can name a more specific reason should override this method instead.
+    async fn apply_with_reason(
+        &self,
+        partition_id: PartitionId,
+        files: &[ParquetFile],
+    ) -> Result<FilterOutcome, DynError> {
+        Ok(self.apply(partition_id, files).await?.into())
+    }
+}
+
+/// The outcome of applying a [`PartitionFilter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterOutcome {
+    /// The partition should be kept.
+    Keep,
+    /// The partition was filtered out, together with a stable, machine-readable reason suitable
+    /// for use as a metrics label.
+    Filtered { reason: &'static str },
+}
+
+impl FilterOutcome {
+    /// Returns `true` if the partition should be kept.
+    pub fn keep(&self) -> bool {
+        matches!(self, Self::Keep)
+    }
+}
+
+impl From<bool> for FilterOutcome {
+    fn from(keep: bool) -> Self {
+        if keep {
+            Self::Keep
+        } else {
+            Self::Filtered { reason: "unknown" }
+        }
+    }
 }
 
 // Simple Partitions filters for testing purposes
@@ -89,3 +134,34 @@ impl FalsePartitionFilter {
         Self
     }
 }
+
+/// Partition filter that never resolves.
+///
+/// Useful for proving that a combinator actually cancels its remaining in-flight children
+/// instead of waiting for all of them to finish.
+#[derive(Debug)]
+pub struct PendingPartitionFilter;
+
+impl Display for PendingPartitionFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "pending")
+    }
+}
+
+#[async_trait]
+impl PartitionFilter for PendingPartitionFilter {
+    async fn apply(
+        &self,
+        _partition_id: PartitionId,
+        _files: &[ParquetFile],
+    ) -> Result<bool, DynError> {
+        std::future::pending().await
+    }
+}
+
+impl PendingPartitionFilter {
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self
+    }
+}
@@ -0,0 +1,65 @@
+use std::fmt::Display;
+
+use async_trait::async_trait;
+use data_types::{ParquetFile, PartitionId};
+
+use crate::error::DynError;
+
+use super::PartitionFilter;
+
+/// Inverts the decision of the wrapped filter.
+///
+/// Errors from the inner filter are propagated unchanged so the partition is still marked as
+/// skipped rather than silently kept or dropped.
+#[derive(Debug)]
+pub struct NotPartitionFilter {
+    inner: Box<dyn PartitionFilter>,
+}
+
+impl NotPartitionFilter {
+    pub fn new(inner: Box<dyn PartitionFilter>) -> Self {
+        Self { inner }
+    }
+}
+
+impl Display for NotPartitionFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "not ({})", self.inner)
+    }
+}
+
+#[async_trait]
+impl PartitionFilter for NotPartitionFilter {
+    async fn apply(
+        &self,
+        partition_id: PartitionId,
+        files: &[ParquetFile],
+    ) -> Result<bool, DynError> {
+        let res = self.inner.apply(partition_id, files).await?;
+        Ok(!res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::partition_filter::{FalsePartitionFilter, TruePartitionFilter};
+
+    #[test]
+    fn test_display() {
+        let filter = NotPartitionFilter::new(Box::new(TruePartitionFilter));
+        assert_eq!(filter.to_string(), "not (true)");
+    }
+
+    #[tokio::test]
+    async fn test_apply() {
+        let filter = NotPartitionFilter::new(Box::new(TruePartitionFilter));
+        assert!(!filter
+            .apply(PartitionId::new(1), &[])
+            .await
+            .unwrap());
+
+        let filter = NotPartitionFilter::new(Box::new(FalsePartitionFilter));
+        assert!(filter.apply(PartitionId::new(1), &[]).await.unwrap());
+    }
+}
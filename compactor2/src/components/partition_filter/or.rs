@@ -0,0 +1,247 @@
+use std::fmt::Display;
+
+use async_trait::async_trait;
+use data_types::{ParquetFile, PartitionId};
+use futures::{stream::FuturesUnordered, StreamExt};
+
+use crate::error::DynError;
+
+use super::{FilterOutcome, PartitionFilter};
+
+/// Combines multiple filters and keeps the partition iff at least one of them would.
+///
+/// By default the child filters are polled concurrently and the combinator short-circuits as
+/// soon as the *earliest-declared* child that is going to decide the outcome has resolved,
+/// dropping the remaining in-flight futures. "Earliest-declared" rather than "first to complete"
+/// matters here: we only settle on a child's `Ok(true)`/`Err` once every child declared before it
+/// has already resolved to `Ok(false)`, so the reported reason for a partition rejected by
+/// multiple children is reproducible across runs rather than a race between completion times.
+/// Callers who rely on strict left-to-right evaluation order (e.g. for logging side effects) can
+/// opt into sequential evaluation via [`Self::new_sequential`].
+#[derive(Debug)]
+pub struct OrPartitionFilter {
+    filters: Vec<Box<dyn PartitionFilter>>,
+    concurrent: bool,
+}
+
+impl OrPartitionFilter {
+    pub fn new(filters: Vec<Box<dyn PartitionFilter>>) -> Self {
+        Self {
+            filters,
+            concurrent: true,
+        }
+    }
+
+    /// Like [`Self::new`] but evaluates the child filters one at a time, in order.
+    pub fn new_sequential(filters: Vec<Box<dyn PartitionFilter>>) -> Self {
+        Self {
+            filters,
+            concurrent: false,
+        }
+    }
+}
+
+impl Display for OrPartitionFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "or(")?;
+        for (i, filter) in self.filters.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{filter}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[async_trait]
+impl PartitionFilter for OrPartitionFilter {
+    async fn apply(
+        &self,
+        partition_id: PartitionId,
+        files: &[ParquetFile],
+    ) -> Result<bool, DynError> {
+        Ok(self.apply_with_reason(partition_id, files).await?.keep())
+    }
+
+    async fn apply_with_reason(
+        &self,
+        partition_id: PartitionId,
+        files: &[ParquetFile],
+    ) -> Result<FilterOutcome, DynError> {
+        if !self.concurrent {
+            let mut first_filtered = None;
+            for filter in &self.filters {
+                let outcome = filter.apply_with_reason(partition_id, files).await?;
+                if outcome.keep() {
+                    return Ok(outcome);
+                }
+                first_filtered.get_or_insert(outcome);
+            }
+            return Ok(first_filtered.unwrap_or(FilterOutcome::Filtered { reason: "unknown" }));
+        }
+
+        let n = self.filters.len();
+        let mut futures: FuturesUnordered<_> = self
+            .filters
+            .iter()
+            .enumerate()
+            .map(|(i, filter)| async move { (i, filter.apply_with_reason(partition_id, files).await) })
+            .collect();
+
+        // Slots for the results of children that have resolved, in declaration order. We only
+        // act on a slot once every slot before it is known to be `Ok(false)` -- that's what lets
+        // us return (and, by dropping `futures`, cancel every child we haven't looked at yet)
+        // without waiting for every child to finish.
+        let mut slots: Vec<Option<Result<FilterOutcome, DynError>>> = (0..n).map(|_| None).collect();
+
+        while let Some((i, res)) = futures.next().await {
+            slots[i] = Some(res);
+
+            let mut j = 0;
+            while j < n {
+                match &slots[j] {
+                    None => break,
+                    Some(Ok(outcome)) if !outcome.keep() => j += 1,
+                    Some(_) => return slots[j].take().unwrap(),
+                }
+            }
+            if j == n {
+                // Every child resolved and none kept the partition; the earliest-declared
+                // child's reason is the most specific one we have.
+                return slots[0].take().unwrap();
+            }
+        }
+
+        Ok(FilterOutcome::Filtered { reason: "unknown" })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use iox_tests::ParquetFileBuilder;
+
+    use super::*;
+    use crate::components::partition_filter::{
+        has_files::HasFilesPartitionFilter, max_files::MaxFilesPartitionFilter,
+        max_parquet_bytes::MaxParquetBytesPartitionFilter, FalsePartitionFilter,
+        PendingPartitionFilter, TruePartitionFilter,
+    };
+
+    #[test]
+    fn test_display() {
+        let filter = OrPartitionFilter::new(vec![
+            Box::new(TruePartitionFilter),
+            Box::new(FalsePartitionFilter),
+        ]);
+        assert_eq!(filter.to_string(), "or(true, false)");
+    }
+
+    #[tokio::test]
+    async fn test_apply_concurrent() {
+        let filter = OrPartitionFilter::new(vec![
+            Box::new(FalsePartitionFilter),
+            Box::new(TruePartitionFilter),
+        ]);
+        assert!(filter.apply(PartitionId::new(1), &[]).await.unwrap());
+
+        let filter = OrPartitionFilter::new(vec![
+            Box::new(FalsePartitionFilter),
+            Box::new(FalsePartitionFilter),
+        ]);
+        assert!(!filter.apply(PartitionId::new(1), &[]).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_apply_sequential() {
+        let filter = OrPartitionFilter::new_sequential(vec![
+            Box::new(TruePartitionFilter),
+            Box::new(FalsePartitionFilter),
+        ]);
+        assert!(filter.apply(PartitionId::new(1), &[]).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_empty() {
+        let filter = OrPartitionFilter::new(vec![]);
+        assert!(!filter.apply(PartitionId::new(1), &[]).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_apply_with_reason_surfaces_deciding_child() {
+        let filter = OrPartitionFilter::new(vec![
+            Box::new(HasFilesPartitionFilter::new()),
+            Box::new(FalsePartitionFilter),
+        ]);
+        assert_eq!(
+            filter
+                .apply_with_reason(PartitionId::new(1), &[])
+                .await
+                .unwrap(),
+            FilterOutcome::Filtered {
+                reason: "no_files"
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_apply_with_reason_multiple_failures_is_deterministic() {
+        // Both children reject this (non-empty, non-zero-byte) file slice, for different
+        // reasons. The reported reason must always be whichever child is declared first,
+        // regardless of completion timing.
+        let files = vec![ParquetFileBuilder::new(1).with_file_size_bytes(10).build()];
+
+        let filter = OrPartitionFilter::new(vec![
+            Box::new(MaxFilesPartitionFilter::new(0)),
+            Box::new(MaxParquetBytesPartitionFilter::new(0)),
+        ]);
+        for _ in 0..20 {
+            assert_eq!(
+                filter
+                    .apply_with_reason(PartitionId::new(1), &files)
+                    .await
+                    .unwrap(),
+                FilterOutcome::Filtered {
+                    reason: "too_many_files"
+                }
+            );
+        }
+
+        let filter = OrPartitionFilter::new(vec![
+            Box::new(MaxParquetBytesPartitionFilter::new(0)),
+            Box::new(MaxFilesPartitionFilter::new(0)),
+        ]);
+        for _ in 0..20 {
+            assert_eq!(
+                filter
+                    .apply_with_reason(PartitionId::new(1), &files)
+                    .await
+                    .unwrap(),
+                FilterOutcome::Filtered {
+                    reason: "over_byte_budget"
+                }
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_apply_concurrent_drops_slower_sibling() {
+        // The deciding child (index 0) keeps the partition; the never-resolving sibling at
+        // index 1 must not be waited on, proving the concurrent path actually cancels
+        // outstanding futures instead of joining all of them.
+        let filter = OrPartitionFilter::new(vec![
+            Box::new(TruePartitionFilter),
+            Box::new(PendingPartitionFilter::new()),
+        ]);
+        let outcome = tokio::time::timeout(
+            Duration::from_millis(500),
+            filter.apply_with_reason(PartitionId::new(1), &[]),
+        )
+        .await
+        .expect("must not wait for the pending sibling")
+        .unwrap();
+        assert_eq!(outcome, FilterOutcome::Keep);
+    }
+}
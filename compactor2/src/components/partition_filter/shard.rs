@@ -0,0 +1,132 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt::Display,
+    hash::{Hash, Hasher},
+};
+
+use async_trait::async_trait;
+use data_types::{ParquetFile, PartitionId};
+
+use crate::error::DynError;
+
+use super::PartitionFilter;
+
+/// Selects partitions whose ID hashes to a given shard.
+///
+/// This allows `shard_count` compactor instances to each run with a distinct `shard_index` and
+/// split the overall partition set between them without any central coordination. The hash is
+/// computed with a fixed seed (not the default randomized `RandomState`) so that the assignment
+/// of partitions to shards is stable across processes and restarts.
+///
+/// This filter should be the last element of the composed `and` chain: sharding is meant to
+/// split the set of partitions that already passed every other (content-based) filter, so
+/// running it earlier could waste a shard's slot on a partition another filter would have
+/// rejected anyway.
+#[derive(Debug)]
+pub struct ShardPartitionFilter {
+    shard_index: usize,
+    shard_count: usize,
+}
+
+impl ShardPartitionFilter {
+    pub fn new(shard_index: usize, shard_count: usize) -> Self {
+        assert!(shard_count > 0, "shard_count must be > 0");
+        assert!(
+            shard_index < shard_count,
+            "shard_index must be < shard_count"
+        );
+        Self {
+            shard_index,
+            shard_count,
+        }
+    }
+
+    fn shard_for(&self, partition_id: PartitionId) -> usize {
+        // Fixed seed so the hash -- and hence the shard assignment -- is stable across
+        // processes and restarts, unlike the default `RandomState` hasher.
+        let mut hasher = DefaultHasher::new();
+        partition_id.get().hash(&mut hasher);
+        (hasher.finish() % self.shard_count as u64) as usize
+    }
+}
+
+impl Display for ShardPartitionFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "shard({}/{})",
+            self.shard_index + 1,
+            self.shard_count
+        )
+    }
+}
+
+#[async_trait]
+impl PartitionFilter for ShardPartitionFilter {
+    async fn apply(
+        &self,
+        partition_id: PartitionId,
+        _files: &[ParquetFile],
+    ) -> Result<bool, DynError> {
+        Ok(self.shard_for(partition_id) == self.shard_index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let filter = ShardPartitionFilter::new(0, 3);
+        assert_eq!(filter.to_string(), "shard(1/3)");
+    }
+
+    #[tokio::test]
+    async fn test_shards_cover_input_without_overlap() {
+        let shard_count = 4;
+        let filters: Vec<_> = (0..shard_count)
+            .map(|i| ShardPartitionFilter::new(i, shard_count))
+            .collect();
+
+        for partition_id in 0..1_000i64 {
+            let partition_id = PartitionId::new(partition_id);
+            let mut matches = 0;
+            for filter in &filters {
+                if filter.apply(partition_id, &[]).await.unwrap() {
+                    matches += 1;
+                }
+            }
+            assert_eq!(
+                matches, 1,
+                "partition {partition_id:?} must be claimed by exactly one shard"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_assignment_is_deterministic() {
+        let a = ShardPartitionFilter::new(1, 5);
+        let b = ShardPartitionFilter::new(1, 5);
+
+        for partition_id in 0..100i64 {
+            let partition_id = PartitionId::new(partition_id);
+            assert_eq!(
+                a.apply(partition_id, &[]).await.unwrap(),
+                b.apply(partition_id, &[]).await.unwrap(),
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "shard_count must be > 0")]
+    fn test_zero_shard_count_panics() {
+        ShardPartitionFilter::new(0, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "shard_index must be < shard_count")]
+    fn test_shard_index_out_of_range_panics() {
+        ShardPartitionFilter::new(3, 3);
+    }
+}
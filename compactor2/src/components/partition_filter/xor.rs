@@ -0,0 +1,78 @@
+use std::fmt::Display;
+
+use async_trait::async_trait;
+use data_types::{ParquetFile, PartitionId};
+
+use crate::error::DynError;
+
+use super::PartitionFilter;
+
+/// Combines two filters, keeping the partition iff exactly one of them would.
+#[derive(Debug)]
+pub struct XorPartitionFilter {
+    a: Box<dyn PartitionFilter>,
+    b: Box<dyn PartitionFilter>,
+}
+
+impl XorPartitionFilter {
+    pub fn new(a: Box<dyn PartitionFilter>, b: Box<dyn PartitionFilter>) -> Self {
+        Self { a, b }
+    }
+}
+
+impl Display for XorPartitionFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}) xor ({})", self.a, self.b)
+    }
+}
+
+#[async_trait]
+impl PartitionFilter for XorPartitionFilter {
+    async fn apply(
+        &self,
+        partition_id: PartitionId,
+        files: &[ParquetFile],
+    ) -> Result<bool, DynError> {
+        let a = self.a.apply(partition_id, files).await?;
+        let b = self.b.apply(partition_id, files).await?;
+        Ok(a ^ b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::partition_filter::{FalsePartitionFilter, TruePartitionFilter};
+
+    #[test]
+    fn test_display() {
+        let filter = XorPartitionFilter::new(
+            Box::new(TruePartitionFilter),
+            Box::new(FalsePartitionFilter),
+        );
+        assert_eq!(filter.to_string(), "(true) xor (false)");
+    }
+
+    #[tokio::test]
+    async fn test_apply() {
+        for (a, b, expected) in [
+            (true, true, false),
+            (true, false, true),
+            (false, true, true),
+            (false, false, false),
+        ] {
+            let make = |v: bool| -> Box<dyn PartitionFilter> {
+                if v {
+                    Box::new(TruePartitionFilter)
+                } else {
+                    Box::new(FalsePartitionFilter)
+                }
+            };
+            let filter = XorPartitionFilter::new(make(a), make(b));
+            assert_eq!(
+                filter.apply(PartitionId::new(1), &[]).await.unwrap(),
+                expected
+            );
+        }
+    }
+}